@@ -58,9 +58,9 @@
 //!
 //! ```json
 //! {
-//!   "code": "YAML-00001",
+//!   "code": "YAML-00002",
 //!   "status": 400,
-//!   "kind": "Invalid YAML data",
+//!   "kind": "Malformed YAML syntax",
 //!   "message": "Failed to deserialize YAML config",
 //!   "context": {
 //!     "input": "invalid: yaml"
@@ -94,22 +94,51 @@
 //! ```
 use cdumay_core::{Error, ErrorConverter, define_errors, define_kinds};
 use std::collections::BTreeMap;
+use std::error::Error as StdError;
 
 define_kinds! {
-    YamlData = (400, "Invalid YAML data")
+    YamlIo = (500, "YAML I/O error"),
+    YamlSyntax = (400, "Malformed YAML syntax"),
+    YamlType = (422, "YAML type mismatch")
 }
 
 define_errors! {
-    DataError = YamlData,
+    IoError = YamlIo,
+    SyntaxError = YamlSyntax,
+    TypeError = YamlType,
 }
 
 /// Struct providing helper functions to convert `serde_yaml::Error`
 /// into typed application errors.
 pub struct YamlErrorConverter;
 
+/// Picks the error kind that best matches a `serde_yaml::Error`.
+///
+/// `serde_yaml` does not expose a machine-readable error category. An error's location is a poor
+/// proxy for "I/O failure" — a derived `Deserialize` calling `serde::de::Error::custom`/
+/// `missing_field` has no location either, yet is a data problem, not an I/O one. Instead this
+/// looks at the error's `source()`: `serde_yaml` only attaches a `std::io::Error` source when the
+/// failure actually came from the underlying reader (e.g. `from_reader`), which is the one case
+/// that should map to a 500. Everything else is classified from the message: `"invalid type"`,
+/// `"missing field"`, ... indicate a type/shape mismatch, anything else a plain syntax error.
+fn classify(err: &serde_yaml::Error) -> fn(String, BTreeMap<String, serde_value::Value>) -> Error {
+    let is_io = err.source().map_or(false, |source| source.downcast_ref::<std::io::Error>().is_some());
+    if is_io {
+        return |text, context| IoError::new().with_message(text).with_details(context).into();
+    }
+    let message = err.to_string();
+    if message.contains("invalid type") || message.contains("missing field") || message.contains("unknown field") || message.contains("invalid value") {
+        |text, context| TypeError::new().with_message(text).with_details(context).into()
+    } else {
+        |text, context| SyntaxError::new().with_message(text).with_details(context).into()
+    }
+}
+
 impl ErrorConverter for YamlErrorConverter {
     type Error = serde_yaml::Error;
-    /// Converts a `serde_yaml::Error` into a structured application `Error`.
+    /// Converts a `serde_yaml::Error` into a structured application `Error`, picking the kind
+    /// (I/O, syntax or type mismatch) that best matches the failure so HTTP-facing callers get
+    /// an accurate status code instead of a blanket 400.
     ///
     /// # Parameters
     /// - `err`: The original `serde_yaml::Error` returned from a YAML operation.
@@ -118,7 +147,72 @@ impl ErrorConverter for YamlErrorConverter {
     ///
     /// # Returns
     /// A typed `Error` with metadata and details included.
-    fn convert(_: &serde_yaml::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> Error {
-        DataError::new().with_message(text).with_details(context).into()
+    fn convert(err: &serde_yaml::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> Error {
+        let mut context = context;
+        if let Some(location) = err.location() {
+            context.insert("line".into(), serde_value::Value::U64(location.line() as u64));
+            context.insert("column".into(), serde_value::Value::U64(location.column() as u64));
+            context.insert("index".into(), serde_value::Value::U64(location.index() as u64));
+        }
+        context.insert("origin".into(), serde_value::Value::String(err.to_string()));
+        classify(err)(text, context)
+    }
+}
+
+impl YamlErrorConverter {
+    /// Converts a `serde_yaml::Error` into a structured application `Error`, additionally
+    /// rendering a rustc-style source snippet (offending line plus a caret under the column)
+    /// under a `snippet` context key.
+    ///
+    /// # Parameters
+    /// - `err`: The original `serde_yaml::Error` returned from a YAML operation.
+    /// - `text`: Custom error message you wish to associate with the failure.
+    /// - `context`: A context to enrich the error with metadata.
+    /// - `source`: The raw YAML text that was parsed, used to locate the offending line.
+    ///
+    /// # Returns
+    /// A typed `Error` with the same metadata as [`convert`](ErrorConverter::convert), plus a
+    /// `snippet` entry when `err` carries a location. No snippet is added otherwise.
+    pub fn convert_with_source(err: &serde_yaml::Error, text: String, context: BTreeMap<String, serde_value::Value>, source: &str) -> Error {
+        let mut context = context;
+        if let Some(location) = err.location() {
+            let lines: Vec<&str> = source.split('\n').collect();
+            let line_idx = location.line().saturating_sub(1).min(lines.len().saturating_sub(1));
+            if let Some(line) = lines.get(line_idx) {
+                let char_count = line.chars().count();
+                let column = location.column().saturating_sub(1).min(char_count);
+                let gutter = " ".repeat(location.line().to_string().len());
+                let snippet = format!("{} | {}\n{} | {}^", location.line(), line, gutter, " ".repeat(column));
+                context.insert("snippet".into(), serde_value::Value::String(snippet));
+            }
+        }
+        Self::convert(err, text, context)
+    }
+
+    /// Reads and deserializes `path` with [`serde_yaml::from_reader`], recording the path under
+    /// a `file` context key so a failure (opening the file or parsing its contents) always says
+    /// which file it came from.
+    ///
+    /// # Parameters
+    /// - `path`: Path to the YAML file to read.
+    /// - `text`: Custom error message you wish to associate with the failure.
+    /// - `context`: A context to enrich the error with metadata.
+    ///
+    /// # Returns
+    /// The deserialized value, or a typed `Error` carrying the `file` and `origin` context keys
+    /// (plus, for parse failures that report a location, `line`/`column`/`index`).
+    pub fn convert_file<T, P>(path: P, text: Option<String>, context: BTreeMap<String, serde_value::Value>) -> cdumay_core::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        P: AsRef<std::path::Path>,
+    {
+        let mut context = context;
+        context.insert("file".into(), serde_value::Value::String(path.as_ref().display().to_string()));
+        let file = std::fs::File::open(&path).map_err(|err| {
+            let mut context = context.clone();
+            context.insert("origin".into(), serde_value::Value::String(err.to_string()));
+            IoError::new().with_message(text.clone().unwrap_or_else(|| err.to_string())).with_details(context).into()
+        })?;
+        serde_yaml::from_reader(std::io::BufReader::new(file)).map_err(|err| Self::convert_error(&err, text, context))
     }
 }
@@ -16,4 +16,19 @@ macro_rules! convert_yaml_result {
             cdumay_yaml::YamlErrorConverter::convert_error(&err, None, std::collections::BTreeMap::new())
         })
     };
-}
\ No newline at end of file
+}
+
+/// Macro to read and deserialize a YAML file into a [`cdumay_core::Result<T>`], recording the
+/// file path in the error context on failure.
+#[macro_export]
+macro_rules! convert_yaml_file {
+    ($path:expr, $context:expr, $text:expr) => {
+        cdumay_yaml::YamlErrorConverter::convert_file($path, Some($text.to_string()), $context)
+    };
+    ($path:expr, $context:expr) => {
+        cdumay_yaml::YamlErrorConverter::convert_file($path, None, $context)
+    };
+    ($path:expr) => {
+        cdumay_yaml::YamlErrorConverter::convert_file($path, None, std::collections::BTreeMap::new())
+    };
+}
@@ -0,0 +1,112 @@
+//! Cross-format bridging between YAML and JSON, routed through the same structured-error
+//! pathway as the rest of the crate.
+//!
+//! ## Usage Example
+//!
+//! ```rust
+//! use cdumay_yaml::transform::yaml_to_json;
+//!
+//! let json = yaml_to_json("name: demo\ndebug: true").unwrap();
+//! assert_eq!(json["name"], "demo");
+//! ```
+use cdumay_core::{Error, ErrorConverter, Result, define_errors, define_kinds};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::errors::YamlErrorConverter;
+
+define_kinds! {
+    YamlJsonBridge = (422, "YAML/JSON bridging error")
+}
+
+define_errors! {
+    BridgeError = YamlJsonBridge,
+}
+
+/// Struct providing helper functions to convert `serde_json::Error`
+/// into typed application errors, mirroring [`YamlErrorConverter`].
+pub struct JsonErrorConverter;
+
+impl ErrorConverter for JsonErrorConverter {
+    type Error = serde_json::Error;
+    /// Converts a `serde_json::Error` into a structured application `Error`.
+    ///
+    /// # Parameters
+    /// - `err`: The original `serde_json::Error` returned from a JSON operation.
+    /// - `text`: Custom error message you wish to associate with the failure.
+    /// - `context`: A context to enrich the error with metadata.
+    ///
+    /// # Returns
+    /// A typed `Error` with metadata and details included.
+    fn convert(err: &serde_json::Error, text: String, context: BTreeMap<String, serde_value::Value>) -> Error {
+        let mut context = context;
+        context.insert("origin".into(), serde_value::Value::String(err.to_string()));
+        BridgeError::new().with_message(text).with_details(context).into()
+    }
+}
+
+/// Recursively converts a `serde_yaml::Value` into a `serde_json::Value`.
+///
+/// JSON has no representation for non-finite floats or non-string mapping keys, so these are
+/// rejected with the offending value's debug representation rather than being silently coerced
+/// (floats to `null`) or panicking.
+fn yaml_value_to_json(value: &serde_yaml::Value) -> std::result::Result<serde_json::Value, String> {
+    match value {
+        serde_yaml::Value::Null => Ok(serde_json::Value::Null),
+        serde_yaml::Value::Bool(b) => Ok(serde_json::Value::Bool(*b)),
+        serde_yaml::Value::Number(n) => match n.as_f64() {
+            Some(f) if !f.is_finite() => Err(format!("{:?}", value)),
+            _ => serde_json::Number::from_str(&n.to_string()).map(serde_json::Value::Number).map_err(|_| format!("{:?}", value)),
+        },
+        serde_yaml::Value::String(s) => Ok(serde_json::Value::String(s.clone())),
+        serde_yaml::Value::Sequence(seq) => seq.iter().map(yaml_value_to_json).collect::<std::result::Result<Vec<_>, _>>().map(serde_json::Value::Array),
+        serde_yaml::Value::Mapping(map) => {
+            let mut object = serde_json::Map::new();
+            for (key, val) in map {
+                let key = match key {
+                    serde_yaml::Value::String(s) => s.clone(),
+                    other => return Err(format!("{:?}", other)),
+                };
+                object.insert(key, yaml_value_to_json(val)?);
+            }
+            Ok(serde_json::Value::Object(object))
+        }
+    }
+}
+
+/// Deserializes `yaml` and re-serializes it as a `serde_json::Value`.
+///
+/// # Parameters
+/// - `yaml`: The raw YAML text to convert.
+///
+/// # Returns
+/// The equivalent JSON value, or a typed `Error` if `yaml` fails to parse or contains a value
+/// with no JSON equivalent (recorded under a `value` context key).
+pub fn yaml_to_json(yaml: &str) -> Result<serde_json::Value> {
+    let mut context = BTreeMap::new();
+    context.insert("input".into(), serde_value::Value::String(yaml.to_string()));
+
+    let value: serde_yaml::Value = serde_yaml::from_str(yaml).map_err(|err| YamlErrorConverter::convert_error(&err, None, context.clone()))?;
+
+    yaml_value_to_json(&value).map_err(|offending| {
+        context.insert("value".into(), serde_value::Value::String(offending));
+        BridgeError::new().with_message("YAML value has no JSON equivalent".to_string()).with_details(context).into()
+    })
+}
+
+/// Deserializes `json` and re-serializes it as a YAML string.
+///
+/// # Parameters
+/// - `json`: The raw JSON text to convert.
+///
+/// # Returns
+/// The equivalent YAML text, or a typed `Error` if `json` fails to parse or `serde_yaml` fails
+/// to re-serialize the resulting value.
+pub fn json_to_yaml(json: &str) -> Result<String> {
+    let mut context = BTreeMap::new();
+    context.insert("input".into(), serde_value::Value::String(json.to_string()));
+
+    let value: serde_json::Value = serde_json::from_str(json).map_err(|err| JsonErrorConverter::convert_error(&err, None, context.clone()))?;
+
+    serde_yaml::to_string(&value).map_err(|err| YamlErrorConverter::convert_error(&err, None, context))
+}
@@ -1,5 +1,6 @@
 use cdumay_core::ErrorConverter;
-use cdumay_yaml::convert_yaml_result;
+use cdumay_yaml::{convert_yaml_file, convert_yaml_result};
+use serde_value::Value;
 use std::collections::BTreeMap;
 
 #[test]
@@ -40,3 +41,48 @@ fn test_convert_result_success() {
     let converted = convert_yaml_result!(result);
     assert!(converted.is_ok());
 }
+
+#[test]
+fn test_convert_yaml_file_missing_file_reports_path_and_origin() {
+    let path = std::env::temp_dir().join("cdumay_yaml_test_missing.yaml");
+    let _ = std::fs::remove_file(&path);
+
+    let converted: cdumay_core::Result<serde_yaml::Value> = convert_yaml_file!(&path);
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    let details = err.details();
+    assert_eq!(details.get("file"), Some(&Value::String(path.display().to_string())));
+    assert!(details.contains_key("origin"));
+}
+
+#[test]
+fn test_convert_yaml_file_malformed_contents_reports_path() {
+    let path = std::env::temp_dir().join("cdumay_yaml_test_malformed.yaml");
+    std::fs::write(&path, "invalid: yaml: :").unwrap();
+
+    let mut context = BTreeMap::new();
+    context.insert("test".to_string(), Value::String("value".to_string()));
+
+    let converted: cdumay_core::Result<serde_yaml::Value> = convert_yaml_file!(&path, context, "Failed to load config");
+    assert!(converted.is_err());
+
+    let err = converted.unwrap_err();
+    assert!(err.message().contains("Failed to load config"));
+    let details = err.details();
+    assert_eq!(details.get("file"), Some(&Value::String(path.display().to_string())));
+    assert!(details.contains_key("test"));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_convert_yaml_file_success() {
+    let path = std::env::temp_dir().join("cdumay_yaml_test_valid.yaml");
+    std::fs::write(&path, "name: demo\ndebug: true").unwrap();
+
+    let converted: cdumay_core::Result<serde_yaml::Value> = convert_yaml_file!(&path);
+    assert!(converted.is_ok());
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,60 @@
+use cdumay_core::ErrorConverter;
+use cdumay_yaml::transform::{json_to_yaml, yaml_to_json};
+
+#[test]
+fn test_yaml_to_json_round_trip() {
+    let json = yaml_to_json("name: demo\ndebug: true\ntags:\n  - a\n  - b").unwrap();
+
+    assert_eq!(json["name"], "demo");
+    assert_eq!(json["debug"], true);
+    assert_eq!(json["tags"][0], "a");
+    assert_eq!(json["tags"][1], "b");
+}
+
+#[test]
+fn test_json_to_yaml_round_trip() {
+    let yaml = json_to_yaml(r#"{"name":"demo","debug":true}"#).unwrap();
+    let value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+
+    assert_eq!(value["name"], serde_yaml::Value::String("demo".to_string()));
+    assert_eq!(value["debug"], serde_yaml::Value::Bool(true));
+}
+
+#[test]
+fn test_yaml_to_json_rejects_non_finite_float() {
+    let result = yaml_to_json("value: .nan");
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(err.message().contains("no JSON equivalent"));
+    assert!(err.details().contains_key("value"));
+}
+
+#[test]
+fn test_yaml_to_json_rejects_non_string_mapping_key() {
+    let result = yaml_to_json("42: answer");
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(err.details().contains_key("value"));
+}
+
+#[test]
+fn test_yaml_to_json_parse_failure_routes_through_yaml_error_converter() {
+    let result = yaml_to_json("invalid: yaml: :");
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(err.details().contains_key("origin"));
+    assert!(err.details().contains_key("input"));
+}
+
+#[test]
+fn test_json_to_yaml_parse_failure_records_input() {
+    let result = json_to_yaml("{not valid json");
+    assert!(result.is_err());
+
+    let err = result.unwrap_err();
+    assert!(err.details().contains_key("origin"));
+    assert!(err.details().contains_key("input"));
+}
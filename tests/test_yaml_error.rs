@@ -1,8 +1,23 @@
 use cdumay_core::ErrorConverter;
 use cdumay_yaml::YamlErrorConverter;
+use serde::Deserialize;
 use serde_value::Value;
 use std::collections::BTreeMap;
 
+struct FailingReader;
+
+impl std::io::Read for FailingReader {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(std::io::ErrorKind::Other, "boom"))
+    }
+}
+
+#[derive(Deserialize)]
+struct Typed {
+    #[allow(dead_code)]
+    count: u32,
+}
+
 #[test]
 fn test_invalid_yaml_returns_custom_error_with_message() {
     let invalid_yaml = "invalid: yaml: :"; // malformed input
@@ -34,7 +49,63 @@ fn test_invalid_yaml_returns_error_with_default_message() {
     let custom_error = YamlErrorConverter::convert_error(&err, None, context.clone());
 
     assert_eq!(custom_error.message(), err.to_string());
-    assert!(custom_error.details().is_empty()); // no context added
+    assert!(custom_error.details().contains_key("origin")); // origin is always set
+}
+
+#[test]
+fn test_snippet_gutter_matches_multi_digit_line_number() {
+    let mut input = "key: value\n".repeat(15);
+    input.push_str("invalid: yaml: :");
+
+    let result = serde_yaml::from_str::<serde_yaml::Value>(&input);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+    assert!(err.location().is_some(), "test requires an error with a location");
+
+    let custom_error = YamlErrorConverter::convert_with_source(&err, "Parse failed".to_string(), BTreeMap::new(), &input);
+
+    let snippet = match custom_error.details().get("snippet") {
+        Some(Value::String(s)) => s.clone(),
+        other => panic!("expected a snippet entry, got {:?}", other),
+    };
+
+    let mut lines = snippet.lines();
+    let first_gutter = lines.next().unwrap().find('|').unwrap();
+    let second_gutter = lines.next().unwrap().find('|').unwrap();
+    assert_eq!(first_gutter, second_gutter, "caret row gutter must match the line-number row gutter");
+}
+
+#[test]
+fn test_classify_true_io_failure_maps_to_500() {
+    let result = serde_yaml::from_reader::<_, serde_yaml::Value>(FailingReader);
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+
+    let custom_error = YamlErrorConverter::convert_error(&err, None, BTreeMap::new());
+    let json = serde_json::to_value(&custom_error).expect("Error should serialize");
+    assert_eq!(json["status"], 500);
+}
+
+#[test]
+fn test_classify_type_mismatch_maps_to_422() {
+    let result = serde_yaml::from_str::<Typed>("count: not-a-number");
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+
+    let custom_error = YamlErrorConverter::convert_error(&err, None, BTreeMap::new());
+    let json = serde_json::to_value(&custom_error).expect("Error should serialize");
+    assert_eq!(json["status"], 422);
+}
+
+#[test]
+fn test_classify_syntax_error_maps_to_400() {
+    let result = serde_yaml::from_str::<serde_yaml::Value>("invalid: yaml: :");
+    assert!(result.is_err());
+    let err = result.unwrap_err();
+
+    let custom_error = YamlErrorConverter::convert_error(&err, None, BTreeMap::new());
+    let json = serde_json::to_value(&custom_error).expect("Error should serialize");
+    assert_eq!(json["status"], 400);
 }
 
 #[test]